@@ -0,0 +1,69 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A fixed-size Bloom filter. Membership is tested/set at `k` bit positions derived from two
+/// independent 64-bit hashes via double hashing: `(h1 + i*h2) mod m` for `i in 0..k`.
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    m: usize,
+    k: u32,
+    seed1: u64,
+    seed2: u64,
+}
+
+impl BloomFilter {
+    pub fn new(m: usize, k: u32, seed1: u64, seed2: u64) -> Self {
+        Self {
+            bits: vec![0u64; m.div_ceil(64)],
+            m,
+            k,
+            seed1,
+            seed2,
+        }
+    }
+
+    fn hash(seed: u64, term: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        term.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn bit_positions(&self, term: &str) -> impl Iterator<Item = usize> + '_ {
+        let h1 = Self::hash(self.seed1, term);
+        let h2 = Self::hash(self.seed2, term);
+        (0..self.k as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % self.m as u64) as usize)
+    }
+
+    pub fn insert(&mut self, term: &str) {
+        for bit in self.bit_positions(term).collect::<Vec<_>>() {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns `false` if `term` is definitely absent, `true` if it may be present (callers must
+    /// verify against the real data to rule out false positives).
+    pub fn might_contain(&self, term: &str) -> bool {
+        self.bit_positions(term)
+            .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserted_terms_are_reported_present() {
+        let mut f = BloomFilter::new(256, 4, 0x1234, 0x5678);
+        f.insert("brown");
+        assert!(f.might_contain("brown"));
+    }
+
+    #[test]
+    fn absent_terms_are_usually_reported_absent() {
+        let mut f = BloomFilter::new(256, 4, 0x1234, 0x5678);
+        f.insert("brown");
+        assert!(!f.might_contain("definitely-not-indexed"));
+    }
+}
@@ -0,0 +1,65 @@
+//! Shared binary encoding for `Column`s and posting lists, used by both the mmap [`crate::snapshot`]
+//! and the block-compressed [`crate::export`] table so the two on-disk formats agree on how a
+//! `Column` and a posting list of keys are laid out byte-for-byte.
+
+use std::io::{self, Read, Write};
+
+use crate::rover::Column;
+
+pub(crate) fn write_column(w: &mut impl Write, column: &Column) -> io::Result<()> {
+    match column {
+        Column::Number(n) => {
+            w.write_all(&[0])?;
+            w.write_all(&n.to_le_bytes())
+        }
+        Column::Str(s) => {
+            w.write_all(&[1])?;
+            w.write_all(&(s.len() as u32).to_le_bytes())?;
+            w.write_all(s.as_bytes())
+        }
+    }
+}
+
+pub(crate) fn read_column(r: &mut impl Read) -> io::Result<Column> {
+    let mut tag = [0u8; 1];
+    r.read_exact(&mut tag)?;
+    match tag[0] {
+        0 => {
+            let mut n = [0u8; 4];
+            r.read_exact(&mut n)?;
+            Ok(Column::Number(i32::from_le_bytes(n)))
+        }
+        1 => {
+            let mut len = [0u8; 4];
+            r.read_exact(&mut len)?;
+            let mut buf = vec![0u8; u32::from_le_bytes(len) as usize];
+            r.read_exact(&mut buf)?;
+            Ok(Column::Str(
+                String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            ))
+        }
+        t => Err(io::Error::new(io::ErrorKind::InvalidData, format!("unknown column tag {t}"))),
+    }
+}
+
+/// Appends a posting list as `(u32 len, bytes)*`, one entry per key, in iteration order.
+pub(crate) fn write_posting_list<K: AsRef<[u8]>>(w: &mut Vec<u8>, keys: impl IntoIterator<Item = K>) {
+    for key in keys {
+        let bytes = key.as_ref();
+        w.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        w.extend_from_slice(bytes);
+    }
+}
+
+/// Splits a `(u32 len, bytes)*` region back into zero-copy key slices.
+pub(crate) fn read_posting_list(mut bytes: &[u8]) -> Vec<&[u8]> {
+    let mut keys = Vec::new();
+    while !bytes.is_empty() {
+        let (len_bytes, rest) = bytes.split_at(4);
+        let len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+        let (key, rest) = rest.split_at(len);
+        keys.push(key);
+        bytes = rest;
+    }
+    keys
+}
@@ -1,4 +1,10 @@
 use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::ops::Bound;
+
+use indexmap::IndexMap;
+
+use crate::text::TextIndex;
 
 /// Column's index (0...255)
 pub type ColumnIndex = u8;
@@ -18,6 +24,84 @@ pub enum Column {
     Str(String),
 }
 
+/// A posting list: the keys indexed under one `Column` value, in the order they were indexed. An
+/// `IndexMap` is used instead of a `Vec` so `remove` finds a key by hash instead of an equality
+/// scan; the removal itself (`shift_remove`) shifts the later keys down, which is O(n) in the
+/// list's length but preserves insertion order for `sort_by_column`.
+pub(crate) type PostingList<K> = IndexMap<K, ()>;
+
+/// A column's posting list, either held as a plain, mutable [`PostingList`] or — after
+/// [`Rover::compress_column_in_place`] — as its delta+RLE [`crate::compress::CompressedPostingList`]
+/// form, which is decoded back to `K` lazily via `decode` on every read instead of a second,
+/// redundant copy being kept resident alongside it. Mutating a compressed column (`insert`/
+/// `shift_remove`) decompresses it back to `Plain` first: compression is a read-time footprint
+/// optimization, not a format meant to support in-place edits.
+pub(crate) enum Entries<K> {
+    Plain(PostingList<K>),
+    Compressed {
+        list: crate::compress::CompressedPostingList,
+        decode: fn(i64) -> K,
+    },
+}
+
+impl<K> Default for Entries<K> {
+    fn default() -> Self {
+        Entries::Plain(PostingList::default())
+    }
+}
+
+impl<K> Entries<K>
+where
+    K: Copy + Eq + Hash,
+{
+    fn len(&self) -> usize {
+        match self {
+            Entries::Plain(p) => p.len(),
+            Entries::Compressed { list, .. } => list.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decoded keys, in the order the representation naturally produces them: insertion order for
+    /// `Plain`, ascending value order for `Compressed` (see [`crate::compress`] for why those
+    /// differ).
+    pub(crate) fn iter_keys(&self) -> Box<dyn Iterator<Item = K> + '_> {
+        match self {
+            Entries::Plain(p) => Box::new(p.keys().copied()),
+            Entries::Compressed { list, decode } => Box::new(list.iter().map(decode)),
+        }
+    }
+
+    fn contains_key(&self, k: &K) -> bool {
+        match self {
+            Entries::Plain(p) => p.contains_key(k),
+            Entries::Compressed { .. } => self.iter_keys().any(|key| key == *k),
+        }
+    }
+
+    fn insert(&mut self, k: K) {
+        self.decompress();
+        let Entries::Plain(p) = self else { unreachable!("decompress() always yields Plain") };
+        p.insert(k, ());
+    }
+
+    fn shift_remove(&mut self, k: &K) {
+        self.decompress();
+        if let Entries::Plain(p) = self {
+            p.shift_remove(k);
+        }
+    }
+
+    fn decompress(&mut self) {
+        if let Entries::Compressed { list, decode } = self {
+            *self = Entries::Plain(list.iter().map(|n| (decode(n), ())).collect());
+        }
+    }
+}
+
 /// Rover is an inmemory indexer, which can be used to index any KV database. A `value_decoder` is
 /// used to transform a value into a vector of Columns. Then, for each column, a HashMap and
 /// BTreeMap are built. A hashmap gives O(1) access, a btree map gives us sorted list.
@@ -27,26 +111,123 @@ where
     V: AsRef<[u8]>,
 {
     // O(1) access (hard requirement)
-    maps: HashMap<ColumnIndex, HashMap<Column, Vec<K>>>,
+    maps: HashMap<ColumnIndex, HashMap<Column, Entries<K>>>,
     // iterating over sorted keys
-    btrees: HashMap<ColumnIndex, BTreeMap<Column, Vec<K>>>,
+    btrees: HashMap<ColumnIndex, BTreeMap<Column, Entries<K>>>,
+    // full-text search over Column::Str values, keyed by the same ColumnIndex as maps/btrees
+    text_indexes: HashMap<ColumnIndex, TextIndex<K>>,
     // a decoder which knows how to transform raw bytes into a vector of Column
     value_decoder: Box<dyn ValueDecoder<V> + 'static>,
 }
 
 impl<K, V> Rover<K, V>
 where
-    K: AsRef<[u8]> + Copy,
+    K: AsRef<[u8]> + Copy + Eq + Hash,
     V: AsRef<[u8]>,
 {
     pub fn new(value_decoder: Box<dyn ValueDecoder<V>>) -> Self {
         Self {
             maps: HashMap::new(),
             btrees: HashMap::new(),
+            text_indexes: HashMap::new(),
             value_decoder,
         }
     }
 
+    /// Serializes the sorted `btrees` index into `path` as a snapshot that [`Rover::open`] can
+    /// later memory-map back in instead of re-decoding every value. See [`crate::snapshot`] for
+    /// the file layout.
+    pub fn save(&self, path: &std::path::Path) -> std::io::Result<()> {
+        crate::snapshot::save(&self.btrees, path)
+    }
+
+    /// Exports the sorted `btrees` index as a block-compressed, sorted table at `path`, openable
+    /// from another process via [`crate::export::Table::open`]. See [`crate::export`] for the
+    /// file layout.
+    pub fn export(&self, path: &std::path::Path) -> std::io::Result<()> {
+        crate::export::export(&self.btrees, path)
+    }
+
+    /// Replaces every posting list under `index`, in both `maps` and `btrees`, with its delta+RLE
+    /// [`crate::compress::CompressedPostingList`] form — the original `IndexMap`s are dropped
+    /// rather than kept resident alongside a second, compressed copy, so this actually shrinks a
+    /// large, low-cardinality column's footprint instead of adding to it. `decode` reconstructs `K`
+    /// from the big-endian `i64` a key's 8 bytes are interpreted as; callers must pass the inverse
+    /// of however `K` encodes an integer (e.g. `i64::to_be_bytes` composed with however `K` wraps a
+    /// `[u8; 8]`). Returns `false` (leaving `index` untouched) if `index` isn't present, or if any
+    /// key isn't exactly 8 bytes.
+    ///
+    /// `get`/`sort_by_column`/`query`/`get_range` decode a compressed column's keys lazily from
+    /// this representation rather than the whole posting list being resident as a `Vec`/`IndexMap`.
+    /// The first subsequent `index_all_columns`/`remove` touching the column decompresses it back
+    /// to a plain, mutable `IndexMap` — compression trades mutation speed for resident footprint,
+    /// it isn't a format meant to support in-place edits.
+    pub fn compress_column_in_place(&mut self, index: ColumnIndex, decode: fn(i64) -> K) -> bool {
+        let Some(btree) = self.btrees.get(&index) else { return false };
+
+        let mut compressed = Vec::with_capacity(btree.len());
+        for (column, entries) in btree {
+            let ints: Option<Vec<i64>> = entries
+                .iter_keys()
+                .map(|k| <[u8; 8]>::try_from(k.as_ref()).ok().map(i64::from_be_bytes))
+                .collect();
+            match ints {
+                Some(ints) => compressed.push((column.clone(), crate::compress::CompressedPostingList::from_keys(&ints))),
+                None => return false,
+            }
+        }
+
+        let map = self.maps.entry(index).or_default();
+        let btree = self.btrees.entry(index).or_default();
+        for (column, list) in compressed {
+            map.insert(
+                column.clone(),
+                Entries::Compressed {
+                    list: list.clone(),
+                    decode,
+                },
+            );
+            btree.insert(column, Entries::Compressed { list, decode });
+        }
+        true
+    }
+
+    /// Rebuilds a `Rover` from already-indexed posting lists, e.g. ones decoded straight from a
+    /// [`crate::snapshot`] without re-running the `value_decoder` over every value.
+    pub(crate) fn from_parts(
+        maps: HashMap<ColumnIndex, HashMap<Column, PostingList<K>>>,
+        btrees: HashMap<ColumnIndex, BTreeMap<Column, PostingList<K>>>,
+        value_decoder: Box<dyn ValueDecoder<V>>,
+    ) -> Self {
+        Self {
+            maps: maps
+                .into_iter()
+                .map(|(i, m)| (i, m.into_iter().map(|(c, p)| (c, Entries::Plain(p))).collect()))
+                .collect(),
+            btrees: btrees
+                .into_iter()
+                .map(|(i, m)| (i, m.into_iter().map(|(c, p)| (c, Entries::Plain(p))).collect()))
+                .collect(),
+            text_indexes: HashMap::new(),
+            value_decoder,
+        }
+    }
+
+    /// Tokenizes `text` and adds it to the full-text index kept under `index`, so it can later be
+    /// found via [`Rover::search_text`]. Unlike `maps`/`btrees`, this isn't populated by
+    /// `index_all_columns`/`value_decoder` — callers index text explicitly, since not every
+    /// `Column::Str` value is prose worth tokenizing.
+    pub fn index_text(&mut self, k: K, text: &str, index: ColumnIndex) {
+        self.text_indexes.entry(index).or_default().index(k, text);
+    }
+
+    /// Full-text searches the index kept under `index` (see [`Rover::index_text`]). `query` may
+    /// end in `*` for a prefix match. Returns an empty `Vec` if nothing was ever indexed under
+    /// `index`.
+    pub fn search_text(&self, index: ColumnIndex, query: &str) -> Vec<K> {
+        self.text_indexes.get(&index).map_or(Vec::new(), |t| t.search(query))
+    }
+
     pub fn index_all_columns(&mut self, k: K, v: V) {
         let columns = self.value_decoder.decode(v);
         for (i, c) in columns.into_iter().enumerate() {
@@ -57,54 +238,157 @@ where
 
     fn index_column(&mut self, k: K, c: Column, index: ColumnIndex) {
         let c_copy = c.clone();
-        // hashmap
-        match self.maps.get_mut(&index) {
-            Some(m) => match m.get_mut(&c) {
-                Some(keys) => keys.push(k),
-                None => {
-                    m.insert(c, vec![k]);
-                }
-            },
-
-            None => {
-                let mut m = HashMap::new();
-                m.insert(c, vec![k]);
-                self.maps.insert(index, m);
-            }
+        self.maps.entry(index).or_default().entry(c).or_default().insert(k);
+        self.btrees.entry(index).or_default().entry(c_copy).or_default().insert(k);
+    }
+
+    /// Removes `k` from the index, decoding its columns from `v` the same way `index_all_columns`
+    /// did. Drops now-empty `Column` entries so btree iteration stays tight.
+    pub fn remove(&mut self, k: K, v: V) {
+        let columns = self.value_decoder.decode(v);
+        for (i, c) in columns.into_iter().enumerate() {
+            self.remove_column(k, c, i as u8);
         }
+    }
 
-        // btreemap
-        match self.btrees.get_mut(&index) {
-            Some(m) => match m.get_mut(&c_copy) {
-                Some(keys) => keys.push(k),
-                None => {
-                    m.insert(c_copy, vec![k]);
-                }
-            },
+    /// Re-indexes `k` when its underlying value changed from `old_v` to `new_v`: removes it under
+    /// `old_v`'s columns, then indexes it under `new_v`'s columns.
+    pub fn reindex(&mut self, k: K, old_v: V, new_v: V) {
+        self.remove(k, old_v);
+        self.index_all_columns(k, new_v);
+    }
 
-            None => {
-                let mut m = BTreeMap::new();
-                m.insert(c_copy, vec![k]);
-                self.btrees.insert(index, m);
-            }
+    fn remove_column(&mut self, k: K, c: Column, index: ColumnIndex) {
+        if let Some(m) = self.maps.get_mut(&index) {
+            remove_from_hashmap(m, &c, &k);
+        }
+        if let Some(m) = self.btrees.get_mut(&index) {
+            remove_from_btreemap(m, &c, &k);
         }
     }
 
-    /// Returns a vector of keys or None if no keys are associated with the given Column.
-    pub fn get(&self, c: Column, index: ColumnIndex) -> Option<&Vec<K>> {
-        self.maps.get(&index).and_then(|m| m.get(&c))
+    /// Returns the keys associated with the given Column, or None if there are none.
+    pub fn get(&self, c: Column, index: ColumnIndex) -> Option<Vec<K>> {
+        self.maps.get(&index).and_then(|m| m.get(&c)).map(|keys| keys.iter_keys().collect())
     }
 
     /// Returns a vector of keys sorted by the given column. Note keys with the same column are in
-    /// order which they were indexed.
+    /// order which they were indexed, unless the column was compressed via
+    /// [`Rover::compress_column_in_place`], in which case they come back in ascending value order.
     pub fn sort_by_column(&self, index: ColumnIndex) -> Vec<K> {
         self.btrees.get(&index).map_or(Vec::new(), |m| {
-            m.values().fold(Vec::new(), |mut acc, x| {
-                acc.append(x.clone().as_mut());
-                acc
-            })
+            m.values().flat_map(|keys| keys.iter_keys()).collect()
+        })
+    }
+
+    /// Returns the keys of every column within `(lower, upper)` in the given column, in sorted
+    /// column order. Keys within a column retain the order in which they were indexed, unless the
+    /// column was compressed via [`Rover::compress_column_in_place`], in which case they come back
+    /// in ascending value order.
+    pub fn get_range(&self, index: ColumnIndex, lower: Bound<Column>, upper: Bound<Column>) -> Vec<K> {
+        self.btrees.get(&index).map_or(Vec::new(), |m| {
+            m.range((lower, upper))
+                .flat_map(|(_, keys)| keys.iter_keys())
+                .collect()
         })
     }
+
+    /// Returns the keys of every `Column::Str` value starting with `prefix`, e.g. for
+    /// autocomplete-style lookups. Implemented as the half-open range `[prefix, prefix')` where
+    /// `prefix'` is `prefix` with its last character incremented to the next valid `char` (see
+    /// `increment_last_char`).
+    pub fn get_prefix(&self, index: ColumnIndex, prefix: &str) -> Vec<K> {
+        let lower = Bound::Included(Column::Str(prefix.to_string()));
+        let upper = match increment_last_char(prefix) {
+            Some(s) => Bound::Excluded(Column::Str(s)),
+            None => Bound::Unbounded,
+        };
+        self.get_range(index, lower, upper)
+    }
+
+    /// Answers a conjunctive query: all `predicates` must match (equality on `(ColumnIndex,
+    /// Column)`), and the result is optionally ordered by `sort_by`. Intersects the `maps`
+    /// posting lists starting from the smallest one and probing the rest via their `HashMap` for
+    /// O(1) membership, so the cost scales with the smallest predicate's selectivity rather than
+    /// the size of the index. A column compressed via [`Rover::compress_column_in_place`] has no
+    /// hash lookup, so probing it is a linear scan of its decoded keys.
+    pub fn query(&self, predicates: &[(ColumnIndex, Column)], sort_by: Option<ColumnIndex>) -> Vec<K> {
+        if predicates.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lists = Vec::with_capacity(predicates.len());
+        for (index, column) in predicates {
+            match self.maps.get(index).and_then(|m| m.get(column)) {
+                Some(keys) => lists.push(keys),
+                // a predicate matching nothing means the whole conjunction matches nothing
+                None => return Vec::new(),
+            }
+        }
+
+        let smallest = lists
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, keys)| keys.len())
+            .map(|(i, _)| i)
+            .unwrap();
+
+        let mut candidates: Vec<K> = lists[smallest].iter_keys().collect();
+        for (i, keys) in lists.iter().enumerate() {
+            if i == smallest {
+                continue;
+            }
+            candidates.retain(|k| keys.contains_key(k));
+        }
+
+        match sort_by {
+            Some(index) => self.order_by_column(candidates, index),
+            None => candidates,
+        }
+    }
+
+    /// Orders `keys` (a subset of already-indexed keys) by where they fall in `sort_by_column`.
+    fn order_by_column(&self, mut keys: Vec<K>, index: ColumnIndex) -> Vec<K> {
+        let mut rank = HashMap::new();
+        for (i, k) in self.sort_by_column(index).into_iter().enumerate() {
+            rank.entry(k).or_insert(i);
+        }
+        keys.sort_by_key(|k| rank.get(k).copied().unwrap_or(usize::MAX));
+        keys
+    }
+}
+
+/// Drops `k` from `c`'s posting list in `m`, and drops `c` itself once its posting list is empty.
+fn remove_from_hashmap<K: Copy + Eq + Hash>(m: &mut HashMap<Column, Entries<K>>, c: &Column, k: &K) {
+    let Some(keys) = m.get_mut(c) else { return };
+    keys.shift_remove(k);
+    if keys.is_empty() {
+        m.remove(c);
+    }
+}
+
+/// Drops `k` from `c`'s posting list in `m`, and drops `c` itself once its posting list is empty.
+fn remove_from_btreemap<K: Copy + Eq + Hash>(m: &mut BTreeMap<Column, Entries<K>>, c: &Column, k: &K) {
+    let Some(keys) = m.get_mut(c) else { return };
+    keys.shift_remove(k);
+    if keys.is_empty() {
+        m.remove(c);
+    }
+}
+
+/// Returns `prefix` with its last character incremented to the next valid `char` (skipping the
+/// surrogate range). Trailing characters that are already `char::MAX` are dropped first, so the
+/// result is still greater than every string starting with `prefix`. Returns `None` if `prefix`
+/// is empty or consists only of `char::MAX`.
+fn increment_last_char(prefix: &str) -> Option<String> {
+    let mut chars: Vec<char> = prefix.chars().collect();
+    while let Some(last) = chars.pop() {
+        if let Some(incremented) = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+            chars.push(incremented);
+            return Some(chars.into_iter().collect());
+        }
+    }
+    None
 }
 
 #[cfg(test)]
@@ -132,7 +416,7 @@ mod tests {
             r.index_all_columns(k, v);
         }
 
-        assert_eq!(Some(&vec!["1"]), r.get(Column::Str("a".to_string()), 0));
+        assert_eq!(Some(vec!["1"]), r.get(Column::Str("a".to_string()), 0));
     }
 
     #[test]
@@ -143,4 +427,160 @@ mod tests {
         }
         assert_eq!(vec!["2", "1", "3"], r.sort_by_column(0));
     }
+
+    #[test]
+    fn get_range_returns_keys_within_bounds() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        for (k, v) in [("1", "a"), ("2", "b"), ("3", "c"), ("4", "d")] {
+            r.index_all_columns(k, v);
+        }
+        let keys = r.get_range(
+            0,
+            Bound::Included(Column::Str("b".to_string())),
+            Bound::Excluded(Column::Str("d".to_string())),
+        );
+        assert_eq!(vec!["2", "3"], keys);
+    }
+
+    #[test]
+    fn get_prefix_returns_matching_keys() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        for (k, v) in [("1", "apple"), ("2", "apricot"), ("3", "banana")] {
+            r.index_all_columns(k, v);
+        }
+        assert_eq!(vec!["1", "2"], r.get_prefix(0, "ap"));
+    }
+
+    #[test]
+    fn remove_drops_the_key_from_both_indexes() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        for (k, v) in [("1", "a"), ("2", "a"), ("3", "b")] {
+            r.index_all_columns(k, v);
+        }
+
+        r.remove("1", "a");
+
+        assert_eq!(Some(vec!["2"]), r.get(Column::Str("a".to_string()), 0));
+        assert_eq!(vec!["2", "3"], r.sort_by_column(0));
+    }
+
+    #[test]
+    fn remove_drops_now_empty_column_entries() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        r.index_all_columns("1", "a");
+
+        r.remove("1", "a");
+
+        assert_eq!(None, r.get(Column::Str("a".to_string()), 0));
+        assert!(r.sort_by_column(0).is_empty());
+    }
+
+    #[test]
+    fn reindex_moves_the_key_to_its_new_column() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        r.index_all_columns("1", "a");
+
+        r.reindex("1", "a", "b");
+
+        assert_eq!(None, r.get(Column::Str("a".to_string()), 0));
+        assert_eq!(Some(vec!["1"]), r.get(Column::Str("b".to_string()), 0));
+    }
+
+    /// Columns are "subject,mailbox,sent_at" split out of a CSV-ish value.
+    struct ThreeColumnValueDecoder {}
+    impl<V> ValueDecoder<V> for ThreeColumnValueDecoder
+    where
+        V: AsRef<[u8]>,
+    {
+        fn decode(&self, v: V) -> Vec<Column> {
+            let s = String::from_utf8(v.as_ref().to_vec()).unwrap();
+            let mut parts = s.split(',');
+            let subject = parts.next().unwrap().to_string();
+            let mailbox: i32 = parts.next().unwrap().parse().unwrap();
+            let sent_at: i32 = parts.next().unwrap().parse().unwrap();
+            vec![
+                Column::Str(subject),
+                Column::Number(mailbox),
+                Column::Number(sent_at),
+            ]
+        }
+    }
+
+    #[test]
+    fn query_intersects_predicates_and_sorts_by_column() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(ThreeColumnValueDecoder {}));
+        for (k, v) in [
+            ("1", "sales,5,30"),
+            ("2", "sales,5,10"),
+            ("3", "sales,6,20"),
+            ("4", "support,5,40"),
+        ] {
+            r.index_all_columns(k, v);
+        }
+
+        let predicates = [
+            (0, Column::Str("sales".to_string())),
+            (1, Column::Number(5)),
+        ];
+        assert_eq!(vec!["2", "1"], r.query(&predicates, Some(2)));
+    }
+
+    #[test]
+    fn search_text_finds_keys_indexed_via_index_text() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        r.index_text("1", "the quick brown fox", 0);
+        r.index_text("2", "a slow brown bear", 0);
+
+        assert_eq!(vec!["1", "2"], r.search_text(0, "brown"));
+        assert_eq!(vec!["1"], r.search_text(0, "quic*"));
+        assert!(r.search_text(1, "brown").is_empty());
+    }
+
+    #[test]
+    fn compress_column_in_place_keeps_get_and_sort_by_column_working() {
+        let mut r: Rover<[u8; 8], &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        for (k, v) in [(5i64, "a"), (1i64, "a"), (3i64, "b")] {
+            r.index_all_columns(k.to_be_bytes(), v);
+        }
+
+        assert!(r.compress_column_in_place(0, i64::to_be_bytes));
+
+        assert_eq!(Some(vec![1i64.to_be_bytes(), 5i64.to_be_bytes()]), r.get(Column::Str("a".to_string()), 0));
+        // Compression reorders by ascending value, so sort_by_column's "indexed order" guarantee
+        // no longer holds for this column — see the doc comment on sort_by_column.
+        assert_eq!(
+            vec![1i64.to_be_bytes(), 5i64.to_be_bytes(), 3i64.to_be_bytes()],
+            r.sort_by_column(0)
+        );
+    }
+
+    #[test]
+    fn compress_column_in_place_returns_false_for_non_integer_keys() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        r.index_all_columns("not-8-bytes", "a");
+
+        assert!(!r.compress_column_in_place(0, |_| "unreachable"));
+    }
+
+    #[test]
+    fn removing_a_key_from_a_compressed_column_decompresses_it_first() {
+        let mut r: Rover<[u8; 8], &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        for (k, v) in [(5i64, "a"), (1i64, "a")] {
+            r.index_all_columns(k.to_be_bytes(), v);
+        }
+        assert!(r.compress_column_in_place(0, i64::to_be_bytes));
+
+        r.remove(1i64.to_be_bytes(), "a");
+
+        assert_eq!(Some(vec![5i64.to_be_bytes()]), r.get(Column::Str("a".to_string()), 0));
+    }
+
+    #[test]
+    fn query_returns_empty_when_a_predicate_matches_nothing() {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(ThreeColumnValueDecoder {}));
+        r.index_all_columns("1", "sales,5,30");
+
+        let predicates = [(0, Column::Str("marketing".to_string()))];
+        assert!(r.query(&predicates, None).is_empty());
+    }
 }
@@ -0,0 +1,276 @@
+//! On-disk persistence for a built `Rover`, so a large index doesn't have to be rebuilt from the
+//! underlying KV store on every restart.
+//!
+//! The file layout is a header table of `(ColumnIndex, Column, offset, len)` entries, in the same
+//! order the source `BTreeMap`s already sort them, followed by the packed posting lists those
+//! entries point into. [`open`] memory-maps the file but only reads the header: [`Mapped::get`]
+//! and [`Mapped::sort_by_column`] slice a posting list's keys straight out of the mapped pages the
+//! first time it's asked for, so the OS pages data in on demand per query rather than the whole
+//! index being decoded onto the heap up front. [`Mapped::rover`] is the exception — it decodes
+//! every entry eagerly so the result is a plain `Rover` that can be mutated and queried through
+//! the usual API.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use memmap2::{Mmap, MmapMut, MmapOptions};
+
+use crate::encoding::{read_column, write_column};
+use crate::rover::{Column, ColumnIndex, Entries, PostingList, Rover, ValueDecoder};
+
+const MAGIC: &[u8; 4] = b"ROVR";
+const VERSION: u32 = 1;
+
+struct HeaderEntry {
+    index: ColumnIndex,
+    column: Column,
+    offset: u64,
+    len: u64,
+}
+
+/// Serializes a `Rover`'s sorted `btrees` index into `path`. Called via [`Rover::save`]. Keys are
+/// written out plainly regardless of whether a column is held compressed in memory — the snapshot
+/// format has its own on-disk representation and doesn't need to mirror `Rover`'s in-memory one.
+pub(crate) fn save<K>(btrees: &HashMap<ColumnIndex, BTreeMap<Column, Entries<K>>>, path: &Path) -> io::Result<()>
+where
+    K: AsRef<[u8]> + Copy + Eq + std::hash::Hash,
+{
+    let mut entries = Vec::new();
+    let mut data = Vec::new();
+
+    for (&index, btree) in btrees {
+        for (column, keys) in btree {
+            let offset = data.len() as u64;
+            crate::encoding::write_posting_list(&mut data, keys.iter_keys());
+            entries.push(HeaderEntry {
+                index,
+                column: column.clone(),
+                offset,
+                len: (data.len() as u64) - offset,
+            });
+        }
+    }
+
+    let mut file = File::create(path)?;
+    file.write_all(MAGIC)?;
+    file.write_all(&VERSION.to_le_bytes())?;
+    file.write_all(&(entries.len() as u64).to_le_bytes())?;
+    for entry in &entries {
+        file.write_all(&[entry.index])?;
+        write_column(&mut file, &entry.column)?;
+        file.write_all(&entry.offset.to_le_bytes())?;
+        file.write_all(&entry.len.to_le_bytes())?;
+    }
+    file.write_all(&data)?;
+    Ok(())
+}
+
+/// A memory-mapped snapshot written by [`save`]. Keeps the mapping alive so keys handed out by
+/// [`Mapped::rover`] can borrow straight from it.
+pub struct Mapped {
+    mapping: Mapping,
+    entries: Vec<HeaderEntry>,
+    data_start: usize,
+}
+
+enum Mapping {
+    ReadOnly(Mmap),
+    CopyOnWrite(MmapMut),
+}
+
+impl Mapping {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Mapping::ReadOnly(m) => &m[..],
+            Mapping::CopyOnWrite(m) => &m[..],
+        }
+    }
+}
+
+/// Opens a snapshot written by [`Rover::save`]. With `read_only` the file is mapped shared and
+/// read-only, serving queries straight from the page cache; otherwise it's mapped copy-on-write.
+/// In either mode the `Rover` rebuilt via [`Mapped::rover`] keeps its indexes on the heap, so
+/// mutating it never touches the file.
+pub fn open(path: &Path, read_only: bool) -> io::Result<Mapped> {
+    let file = File::open(path)?;
+    let mapping = if read_only {
+        Mapping::ReadOnly(unsafe { Mmap::map(&file)? })
+    } else {
+        Mapping::CopyOnWrite(unsafe { MmapOptions::new().map_copy(&file)? })
+    };
+
+    let bytes = mapping.as_slice();
+    let mut cursor = io::Cursor::new(bytes);
+
+    let mut magic = [0u8; 4];
+    cursor.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "not a rover snapshot"));
+    }
+    let mut version = [0u8; 4];
+    cursor.read_exact(&mut version)?;
+    if u32::from_le_bytes(version) != VERSION {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "unsupported snapshot version"));
+    }
+
+    let mut count_buf = [0u8; 8];
+    cursor.read_exact(&mut count_buf)?;
+    let count = u64::from_le_bytes(count_buf);
+
+    let mut entries = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let mut index = [0u8; 1];
+        cursor.read_exact(&mut index)?;
+        let column = read_column(&mut cursor)?;
+        let mut offset = [0u8; 8];
+        cursor.read_exact(&mut offset)?;
+        let mut len = [0u8; 8];
+        cursor.read_exact(&mut len)?;
+        entries.push(HeaderEntry {
+            index: index[0],
+            column,
+            offset: u64::from_le_bytes(offset),
+            len: u64::from_le_bytes(len),
+        });
+    }
+
+    let data_start = cursor.position() as usize;
+    Ok(Mapped {
+        mapping,
+        entries,
+        data_start,
+    })
+}
+
+impl Mapped {
+    /// Looks up the keys for `(index, column)`, decoding only that one posting list's bytes out of
+    /// the mapping rather than touching any other entry.
+    pub fn get(&self, index: ColumnIndex, column: &Column) -> Option<Vec<&[u8]>> {
+        let entry = self.entries.iter().find(|e| e.index == index && &e.column == column)?;
+        Some(self.decode_entry(entry))
+    }
+
+    /// Returns the keys of every column under `index`, in sorted column order, decoding each
+    /// column's posting list lazily as it's visited.
+    pub fn sort_by_column(&self, index: ColumnIndex) -> Vec<&[u8]> {
+        self.entries
+            .iter()
+            .filter(|e| e.index == index)
+            .flat_map(|e| self.decode_entry(e))
+            .collect()
+    }
+
+    fn decode_entry(&self, entry: &HeaderEntry) -> Vec<&[u8]> {
+        let start = self.data_start + entry.offset as usize;
+        let end = start + entry.len as usize;
+        decode_posting_list(&self.mapping.as_slice()[start..end])
+    }
+
+    /// Rebuilds a `Rover` whose posting lists borrow keys straight from this mapping, decoding
+    /// every entry eagerly up front so the result is an ordinary, mutable `Rover`. For read-only
+    /// point/range lookups that stay lazy, prefer [`Mapped::get`] / [`Mapped::sort_by_column`]
+    /// directly instead of going through a `Rover` at all. `value_decoder` is only needed to
+    /// satisfy `Rover`'s type, since further values may still be indexed via `index_all_columns`
+    /// after the snapshot is loaded; decoding isn't re-run over snapshot data.
+    pub fn rover<'a, V>(&'a self, value_decoder: Box<dyn ValueDecoder<V>>) -> Rover<&'a [u8], V>
+    where
+        V: AsRef<[u8]>,
+    {
+        let mut maps: HashMap<ColumnIndex, HashMap<Column, PostingList<&'a [u8]>>> = HashMap::new();
+        let mut btrees: HashMap<ColumnIndex, BTreeMap<Column, PostingList<&'a [u8]>>> = HashMap::new();
+
+        for entry in &self.entries {
+            let keys: PostingList<&'a [u8]> = self.decode_entry(entry).into_iter().map(|k| (k, ())).collect();
+
+            maps.entry(entry.index)
+                .or_default()
+                .insert(entry.column.clone(), keys.clone());
+            btrees
+                .entry(entry.index)
+                .or_default()
+                .insert(entry.column.clone(), keys);
+        }
+
+        Rover::from_parts(maps, btrees, value_decoder)
+    }
+}
+
+/// Splits a packed `(u32 len, bytes)*` region back into zero-copy key slices.
+fn decode_posting_list(bytes: &[u8]) -> Vec<&[u8]> {
+    crate::encoding::read_posting_list(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rover::{Rover, ValueDecoder};
+
+    struct SingleStringValueDecoder {}
+    impl<V> ValueDecoder<V> for SingleStringValueDecoder
+    where
+        V: AsRef<[u8]>,
+    {
+        fn decode(&self, v: V) -> Vec<Column> {
+            vec![Column::Str(String::from_utf8(v.as_ref().to_vec()).unwrap())]
+        }
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("rover-snapshot-test-{}-{}", std::process::id(), name))
+    }
+
+    fn save_sample(path: &Path) {
+        let mut r: Rover<&str, &str> = Rover::new(Box::new(SingleStringValueDecoder {}));
+        for (k, v) in [("1", "b"), ("2", "a"), ("3", "a"), ("4", "c")] {
+            r.index_all_columns(k, v);
+        }
+        r.save(path).unwrap();
+    }
+
+    #[test]
+    fn save_then_open_round_trips_get_and_sort_by_column() {
+        let path = temp_path("round-trip");
+        save_sample(&path);
+
+        let mapped = open(&path, true).unwrap();
+
+        assert_eq!(Some(vec![b"2".as_slice(), b"3".as_slice()]), mapped.get(0, &Column::Str("a".to_string())));
+        assert_eq!(None, mapped.get(0, &Column::Str("z".to_string())));
+        assert_eq!(vec![b"2", b"3", b"1", b"4"], mapped.sort_by_column(0));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn open_read_only_maps_the_file_immutably() {
+        let path = temp_path("read-only");
+        save_sample(&path);
+
+        let mapped = open(&path, true).unwrap();
+        assert!(matches!(mapped.mapping, Mapping::ReadOnly(_)));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn open_copy_on_write_lets_the_rebuilt_rover_be_mutated() {
+        let path = temp_path("copy-on-write");
+        save_sample(&path);
+
+        let mapped = open(&path, false).unwrap();
+        assert!(matches!(mapped.mapping, Mapping::CopyOnWrite(_)));
+
+        let mut rover = mapped.rover(Box::new(SingleStringValueDecoder {}));
+        assert_eq!(Some(vec![b"2".as_slice(), b"3".as_slice()]), rover.get(Column::Str("a".to_string()), 0));
+
+        rover.index_all_columns(b"5".as_slice(), "a");
+        assert_eq!(
+            Some(vec![b"2".as_slice(), b"3".as_slice(), b"5".as_slice()]),
+            rover.get(Column::Str("a".to_string()), 0)
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::bloom::BloomFilter;
+
+const BLOOM_BITS: usize = 1024;
+const BLOOM_HASHES: u32 = 4;
+const SEED1: u64 = 0x5bd1_e995_51e5_a8c7;
+const SEED2: u64 = 0xc2b2_ae35_1a05_0c5b;
+const NGRAM_SIZES: [usize; 2] = [2, 3];
+
+/// TextIndex adds full-text search over string values a `Rover` can't express with exact-match
+/// `get`: indexing tokenizes a document into lowercase terms, builds one Bloom filter per key
+/// over those terms and their character n-grams, and keeps an exact `Term -> keys` posting list.
+/// A query hashes the same way and tests the filter first (cheap candidate pruning: any unset bit
+/// means the key definitely doesn't match), then verifies candidates against the real tokens to
+/// drop false positives.
+pub struct TextIndex<K> {
+    // keys in the order they were indexed, so search results have a deterministic order instead
+    // of following `filters`'/`terms_by_key`'s HashMap iteration order
+    insertion_order: Vec<K>,
+    filters: HashMap<K, BloomFilter>,
+    terms_by_key: HashMap<K, Vec<String>>,
+    postings: HashMap<String, Vec<K>>,
+}
+
+impl<K> TextIndex<K>
+where
+    K: Copy + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self {
+            insertion_order: Vec::new(),
+            filters: HashMap::new(),
+            terms_by_key: HashMap::new(),
+            postings: HashMap::new(),
+        }
+    }
+
+    /// Tokenizes `text` and indexes it under `k`.
+    pub fn index(&mut self, k: K, text: &str) {
+        let terms = tokenize(text);
+
+        let mut filter = BloomFilter::new(BLOOM_BITS, BLOOM_HASHES, SEED1, SEED2);
+        for term in &terms {
+            filter.insert(term);
+            for size in NGRAM_SIZES {
+                for ngram in ngrams(term, size) {
+                    filter.insert(&ngram);
+                }
+            }
+            self.postings.entry(term.clone()).or_default().push(k);
+        }
+
+        self.insertion_order.push(k);
+        self.filters.insert(k, filter);
+        self.terms_by_key.insert(k, terms);
+    }
+
+    /// Exact term lookup via the posting list, bypassing the Bloom filter entirely.
+    pub fn get_term(&self, term: &str) -> Option<&Vec<K>> {
+        self.postings.get(&term.to_lowercase())
+    }
+
+    /// Searches for `query`, which may end in `*` for a prefix match (e.g. `"quic*"`). Candidates
+    /// are pruned using the per-key Bloom filter, then verified against the key's real tokens.
+    pub fn search(&self, query: &str) -> Vec<K> {
+        let query = query.to_lowercase();
+        match query.strip_suffix('*') {
+            Some(prefix) => self.search_prefix(prefix),
+            None => self.search_exact(&query),
+        }
+    }
+
+    fn search_exact(&self, term: &str) -> Vec<K> {
+        self.candidates(term)
+            .filter(|k| {
+                self.terms_by_key
+                    .get(k)
+                    .is_some_and(|terms| terms.iter().any(|t| t == term))
+            })
+            .copied()
+            .collect()
+    }
+
+    fn search_prefix(&self, prefix: &str) -> Vec<K> {
+        // A probe ngram, not necessarily present in the prefix's own filter bits, is used to
+        // narrow candidates when the prefix is long enough; short prefixes fall back to scanning
+        // every indexed key since there's nothing useful to hash against.
+        let probe = NGRAM_SIZES
+            .iter()
+            .find(|&&size| prefix.chars().count() >= size)
+            .map(|&size| ngrams(prefix, size).next().unwrap());
+
+        let candidates: Box<dyn Iterator<Item = &K>> = match &probe {
+            Some(ngram) => Box::new(self.candidates(ngram)),
+            None => Box::new(self.insertion_order.iter()),
+        };
+
+        candidates
+            .filter(|k| {
+                self.terms_by_key
+                    .get(k)
+                    .is_some_and(|terms| terms.iter().any(|t| t.starts_with(prefix)))
+            })
+            .copied()
+            .collect()
+    }
+
+    fn candidates<'a>(&'a self, needle: &'a str) -> impl Iterator<Item = &'a K> {
+        self.insertion_order
+            .iter()
+            .filter(move |k| self.filters[k].might_contain(needle))
+    }
+}
+
+impl<K> Default for TextIndex<K>
+where
+    K: Copy + Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lowercases `text`, splits on non-alphanumeric boundaries and drops empty tokens.
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn ngrams(term: &str, n: usize) -> impl Iterator<Item = String> + '_ {
+    let chars: Vec<char> = term.chars().collect();
+    (0..chars.len().saturating_sub(n - 1)).map(move |i| chars[i..i + n].iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_term_search_finds_indexed_keys() {
+        let mut idx: TextIndex<&str> = TextIndex::new();
+        idx.index("1", "the quick brown fox");
+        idx.index("2", "a slow brown bear");
+
+        assert_eq!(vec!["1", "2"], idx.search("brown"));
+        assert_eq!(vec!["1"], idx.search("quick"));
+    }
+
+    #[test]
+    fn prefix_search_matches_term_prefixes() {
+        let mut idx: TextIndex<&str> = TextIndex::new();
+        idx.index("1", "the quick brown fox");
+        idx.index("2", "a slow brown bear");
+
+        assert_eq!(vec!["1"], idx.search("quic*"));
+    }
+
+    #[test]
+    fn get_term_reads_the_exact_posting_list() {
+        let mut idx: TextIndex<&str> = TextIndex::new();
+        idx.index("1", "quick brown fox");
+
+        assert_eq!(Some(&vec!["1"]), idx.get_term("brown"));
+        assert_eq!(None, idx.get_term("slow"));
+    }
+}